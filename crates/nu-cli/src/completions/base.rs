@@ -0,0 +1,72 @@
+use nu_protocol::{
+    engine::{CommandType, Stack, StateWorkingSet},
+    Span,
+};
+use nu_utils::IgnoreCaseExt;
+use reedline::Suggestion;
+
+use super::CompletionOptions;
+
+/// A completion candidate plus the metadata the completion menu needs to
+/// render and group it, layered on top of the raw reedline [`Suggestion`].
+#[derive(Clone, Debug)]
+pub struct SemanticSuggestion {
+    pub suggestion: Suggestion,
+    pub kind: Option<SuggestionKind>,
+    /// Char positions within `suggestion.value` that matched the search
+    /// needle, so the menu can highlight them. Empty when the match
+    /// algorithm has no natural positions to report (e.g. regex matches,
+    /// or suggestions that never went through `NuMatcher`).
+    pub match_indices: Vec<usize>,
+}
+
+/// What a completion suggestion stands for, so the menu can style and
+/// group results without re-deriving it from the raw value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SuggestionKind {
+    /// Something from outside Nushell: an external executable found on
+    /// `PATH`, or an argument candidate reported by an external command.
+    External,
+    /// A directory.
+    Directory,
+    /// A Nushell command of the given type (builtin, custom, alias, ...).
+    Command(CommandType),
+}
+
+/// Something that can fetch completion suggestions for a partially-typed
+/// span of source text.
+pub trait Completer {
+    #[allow(clippy::too_many_arguments)]
+    fn fetch(
+        &mut self,
+        working_set: &StateWorkingSet,
+        stack: &Stack,
+        prefix: Vec<u8>,
+        span: Span,
+        offset: usize,
+        pos: usize,
+        options: &CompletionOptions,
+    ) -> Vec<SemanticSuggestion>;
+}
+
+/// Direction to sort suggestions that were assembled outside of
+/// `NuMatcher` (which already keeps its results sorted as it inserts).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    Ascending,
+    None,
+}
+
+/// Sorts `suggestions` by their (case-folded) value. `prefix` isn't used
+/// for ordering itself, only kept so callers can match `NuMatcher`'s
+/// `(needle, haystacks)` call shape when swapping between the two.
+pub fn sort_suggestions(
+    _prefix: &str,
+    mut suggestions: Vec<SemanticSuggestion>,
+    sort_by: SortBy,
+) -> Vec<SemanticSuggestion> {
+    if sort_by == SortBy::Ascending {
+        suggestions.sort_by_cached_key(|s| s.suggestion.value.to_folded_case());
+    }
+    suggestions
+}
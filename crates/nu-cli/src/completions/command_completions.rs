@@ -1,18 +1,23 @@
 use std::collections::HashSet;
 
-use crate::{
-    completions::{Completer, CompletionOptions},
-    SuggestionKind,
-};
+use crate::completions::{Completer, CompletionOptions, SuggestionKind};
+use nu_engine::env_to_string;
 use nu_parser::FlatShape;
 use nu_protocol::{
-    engine::{CachedFile, Stack, StateWorkingSet},
+    engine::{CachedFile, EngineState, Stack, StateWorkingSet},
     Span,
 };
+use nu_utils::get_ls_colors;
 use reedline::Suggestion;
 
 use super::{completion_options::NuMatcher, SemanticSuggestion};
 
+/// Completes internal command names and, when [`enable_external_completion`]
+/// is set, external executable names, tagging the latter with
+/// [`SuggestionKind::External`] (defined in `completions::base` alongside
+/// the `Completer` trait, both re-exported from `completions/mod.rs`).
+///
+/// [`enable_external_completion`]: nu_protocol::Config::enable_external_completion
 pub struct CommandCompletion {
     flattened: Vec<(Span, FlatShape)>,
     flat_shape: FlatShape,
@@ -35,6 +40,7 @@ impl CommandCompletion {
     fn external_command_completion(
         &self,
         working_set: &StateWorkingSet,
+        stack: &Stack,
         sugg_span: reedline::Span,
         matched_internal: &HashSet<String>,
         matcher: &mut NuMatcher<SemanticSuggestion>,
@@ -50,6 +56,7 @@ impl CommandCompletion {
         let Ok(paths) = paths.as_list() else {
             return;
         };
+        let ls_colors = get_ls_colors_for_executables(working_set.permanent_state, stack);
         for path in paths {
             let path = path.coerce_str().unwrap_or_default();
 
@@ -70,6 +77,16 @@ impl CommandCompletion {
                     continue;
                 };
                 if !executables.contains(&name) && is_executable::is_executable(item.path()) {
+                    let style = ls_colors.as_ref().map(|lsc| {
+                        lsc.style_for_path_with_metadata(
+                            &item.path(),
+                            std::fs::symlink_metadata(item.path()).ok().as_ref(),
+                        )
+                        .map(lscolors::Style::to_nu_ansi_term_style)
+                        .unwrap_or_default()
+                    });
+                    let description = Some(path.to_string());
+
                     let name = if matched_internal.contains(&name) {
                         format!("^{}", name)
                     } else {
@@ -78,14 +95,14 @@ impl CommandCompletion {
                     let added = matcher.add_semantic_suggestion(SemanticSuggestion {
                         suggestion: Suggestion {
                             value: name.clone(),
-                            description: None,
-                            style: None,
+                            description,
+                            style,
                             extra: None,
                             span: sugg_span,
                             append_whitespace: true,
                         },
-                        // TODO: is there a way to create a test?
-                        kind: None,
+                        kind: Some(SuggestionKind::External),
+                        match_indices: Vec::new(),
                     });
                     if added {
                         executables.insert(name);
@@ -98,6 +115,7 @@ impl CommandCompletion {
     fn complete_commands(
         &self,
         working_set: &StateWorkingSet,
+        stack: &Stack,
         span: Span,
         offset: usize,
         find_externals: bool,
@@ -122,6 +140,7 @@ impl CommandCompletion {
                     append_whitespace: true,
                 },
                 kind: Some(SuggestionKind::Command(typ)),
+                match_indices: Vec::new(),
             });
             if added {
                 matched_internal.insert(name);
@@ -131,21 +150,40 @@ impl CommandCompletion {
         if find_externals {
             self.external_command_completion(
                 working_set,
+                stack,
                 sugg_span,
                 &matched_internal,
                 &mut matcher,
             );
         }
 
-        matcher.results()
+        matcher.results_tagged()
     }
 }
 
+/// Fetches `LS_COLORS` the same way file/directory completions do, so
+/// external executables can be styled by the same file-metadata rules as
+/// paths are.
+fn get_ls_colors_for_executables(
+    engine_state: &EngineState,
+    stack: &Stack,
+) -> Option<lscolors::LsColors> {
+    (engine_state.config.use_ls_colors_completions && engine_state.config.use_ansi_coloring).then(
+        || {
+            let ls_colors_env_str = match stack.get_env_var(engine_state, "LS_COLORS") {
+                Some(v) => env_to_string("LS_COLORS", &v, engine_state, stack).ok(),
+                None => None,
+            };
+            get_ls_colors(ls_colors_env_str)
+        },
+    )
+}
+
 impl Completer for CommandCompletion {
     fn fetch(
         &mut self,
         working_set: &StateWorkingSet,
-        _stack: &Stack,
+        stack: &Stack,
         _prefix: Vec<u8>,
         span: Span,
         offset: usize,
@@ -173,6 +211,7 @@ impl Completer for CommandCompletion {
         let subcommands = if let Some(last) = last {
             self.complete_commands(
                 working_set,
+                stack,
                 Span::new(last.0.start, pos),
                 offset,
                 false,
@@ -199,6 +238,7 @@ impl Completer for CommandCompletion {
             }
             self.complete_commands(
                 working_set,
+                stack,
                 span,
                 offset,
                 config.enable_external_completion,
@@ -1,4 +1,4 @@
-use crate::{completions::CompletionOptions, SemanticSuggestion};
+use crate::completions::{CompletionOptions, SemanticSuggestion};
 use nu_ansi_term::Style;
 use nu_engine::env_to_string;
 use nu_path::{expand_to_real_path, home_dir};
@@ -67,6 +67,12 @@ fn complete_rec(
             built.parts.push(entry_name.clone());
             built.isdir = entry_isdir;
 
+            if let Some(exclude) = &options.exclude {
+                if exclude.is_match(built.parts.join(MAIN_SEPARATOR_STR)) {
+                    continue;
+                }
+            }
+
             if !dir || entry_isdir {
                 matcher.add(entry_name, built);
             }
@@ -86,6 +92,34 @@ fn complete_rec(
     }
 }
 
+/// Whether accepting a suggestion should keep composing the path (re-trigger
+/// completion inside the directory just entered) or commit it as a finished
+/// word, mirroring tab-composes/enter-confirms in modern path pickers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionIntent {
+    /// A directory: insert it and keep completing.
+    Navigate,
+    /// A finished file or argument: insert it and stop there.
+    Complete,
+}
+
+impl CompletionIntent {
+    /// Decided purely from `isdir`, not from the (possibly `escape_path`'d)
+    /// displayed value: a quoted/backtick-wrapped directory name like
+    /// `` `my dir/` `` doesn't end in a separator even though it is one.
+    pub fn for_path(isdir: bool) -> Self {
+        if isdir {
+            Self::Navigate
+        } else {
+            Self::Complete
+        }
+    }
+
+    pub fn append_whitespace(self) -> bool {
+        matches!(self, Self::Complete)
+    }
+}
+
 #[derive(Debug)]
 enum OriginalCwd {
     None,
@@ -131,7 +165,7 @@ pub fn complete_item(
     options: &CompletionOptions,
     engine_state: &EngineState,
     stack: &Stack,
-) -> Vec<(nu_protocol::Span, PathBuf, String, Option<Style>)> {
+) -> Vec<(nu_protocol::Span, PathBuf, String, Option<Style>, bool)> {
     let partial = surround_remove(partial);
     let isdir = partial.ends_with(is_separator);
     let cwd_pathbufs: Vec<_> = cwds.iter().map(|cwd| PathBuf::from(cwd.as_ref())).collect();
@@ -213,11 +247,35 @@ pub fn complete_item(
             .map(lscolors::Style::to_nu_ansi_term_style)
             .unwrap_or_default()
         });
-        (span, cwd, escape_path(path, want_directory), style)
+        (span, cwd, escape_path(path, want_directory), style, isdir)
     })
     .collect()
 }
 
+/// The trailing path segment (file name or final directory component),
+/// marked with a trailing separator for directories, used as the readable
+/// label for a completion whose `value` is the full (possibly long) path.
+pub fn display_basename(value: &str, isdir: bool) -> String {
+    let trimmed = value
+        .trim_matches(['`', '\'', '"'])
+        .trim_end_matches(is_separator);
+    let base = Path::new(trimmed)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| trimmed.to_string());
+    if isdir {
+        format!("{base}{SEP}")
+    } else {
+        base
+    }
+}
+
+/// Number of path components in a suggestion's value, used to sort nearer
+/// (shallower) completions ahead of deeply nested ones.
+pub fn path_depth(suggestion: &SemanticSuggestion) -> usize {
+    Path::new(&suggestion.suggestion.value).components().count()
+}
+
 // Fix files or folders with quotes or hashes
 pub fn escape_path(path: String, dir: bool) -> String {
     // make glob pattern have the highest priority.
@@ -1,10 +1,12 @@
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use nu_parser::trim_quotes_str;
 use nu_protocol::CompletionAlgorithm;
 use nu_utils::IgnoreCaseExt;
-use std::{borrow::Cow, fmt::Display};
+use regex::{Regex, RegexBuilder};
+use std::{borrow::Cow, cmp::Reverse, fmt::Display};
 
-use crate::SemanticSuggestion;
+use crate::completions::SemanticSuggestion;
 
 /// Describes how suggestions should be matched.
 #[derive(Copy, Clone, Debug)]
@@ -20,6 +22,12 @@ pub enum MatchAlgorithm {
     /// Example:
     /// "git checkout" is matched by "gco"
     Fuzzy,
+
+    /// Only show suggestions which match the given input as a regular expression
+    ///
+    /// Example:
+    /// "git switch"/"git checkout" are matched by "^git (sw|co)"
+    Regex,
 }
 
 impl From<CompletionAlgorithm> for MatchAlgorithm {
@@ -27,6 +35,7 @@ impl From<CompletionAlgorithm> for MatchAlgorithm {
         match value {
             CompletionAlgorithm::Prefix => MatchAlgorithm::Prefix,
             CompletionAlgorithm::Fuzzy => MatchAlgorithm::Fuzzy,
+            CompletionAlgorithm::Regex => MatchAlgorithm::Regex,
         }
     }
 }
@@ -38,6 +47,7 @@ impl TryFrom<String> for MatchAlgorithm {
         match value.as_str() {
             "prefix" => Ok(Self::Prefix),
             "fuzzy" => Ok(Self::Fuzzy),
+            "regex" => Ok(Self::Regex),
             _ => Err(InvalidMatchAlgorithm::Unknown),
         }
     }
@@ -50,9 +60,23 @@ pub struct NuMatcher<T> {
     state: State<T>,
 }
 
+/// Composite ranking key for fuzzy matches: primarily the skim score
+/// (highest first), then tie-broken by the earliest matched position, then
+/// haystack length, then case-folded alphabetical order, so equal-score
+/// matches still sort deterministically instead of in insertion order.
+type FuzzySortKey = (Reverse<i64>, usize, usize, String);
+
 enum State<T> {
     Prefix { items: Vec<(String, T)> },
-    Fuzzy { items: Vec<(i64, T)> },
+    /// Alongside the item, keeps the char positions in the haystack that
+    /// `fuzzy_indices` matched, so callers can highlight them.
+    Fuzzy {
+        items: Vec<(FuzzySortKey, Vec<usize>, T)>,
+    },
+    Regex {
+        re: Option<Regex>,
+        items: Vec<(String, T)>,
+    },
 }
 
 impl<T> NuMatcher<T> {
@@ -79,6 +103,23 @@ impl<T> NuMatcher<T> {
                 positional: options.positional,
                 state: State::Fuzzy { items: Vec::new() },
             },
+            MatchAlgorithm::Regex => {
+                // An invalid pattern falls back to matching everything, rather
+                // than panicking or hiding every suggestion.
+                let re = RegexBuilder::new(&needle)
+                    .case_insensitive(!options.case_sensitive)
+                    .build()
+                    .ok();
+                NuMatcher {
+                    needle,
+                    case_sensitive: options.case_sensitive,
+                    positional: options.positional,
+                    state: State::Regex {
+                        re,
+                        items: Vec::new(),
+                    },
+                }
+            }
         }
     }
 
@@ -117,16 +158,40 @@ impl<T> NuMatcher<T> {
                 } else {
                     matcher = matcher.ignore_case();
                 }
-                let Some(score) = matcher.fuzzy_match(haystack, &self.needle) else {
+                let Some((score, indices)) = matcher.fuzzy_indices(haystack, &self.needle) else {
                     return false;
                 };
+                let key = (
+                    Reverse(score),
+                    indices.first().copied().unwrap_or(0),
+                    haystack.chars().count(),
+                    haystack.to_folded_case(),
+                );
+
+                let insert_ind = match items.binary_search_by(|(other_key, ..)| other_key.cmp(&key))
+                {
+                    Ok(i) => i,
+                    Err(i) => i,
+                };
+                items.insert(insert_ind, (key, indices, item));
+
+                true
+            }
+            State::Regex { re, items } => {
+                let matches = match re {
+                    Some(re) => re.is_match(haystack),
+                    None => true,
+                };
+                if !matches {
+                    return false;
+                }
 
                 let insert_ind =
-                    match items.binary_search_by(|(other_score, _)| other_score.cmp(&score)) {
+                    match items.binary_search_by(|(other, _)| other.as_str().cmp(haystack)) {
                         Ok(i) => i,
                         Err(i) => i,
                     };
-                items.insert(insert_ind, (score, item));
+                items.insert(insert_ind, (haystack.to_string(), item));
 
                 true
             }
@@ -136,7 +201,38 @@ impl<T> NuMatcher<T> {
     pub fn results(self) -> Vec<T> {
         match self.state {
             State::Prefix { items } => items.into_iter().map(|(_, item)| item).collect(),
-            State::Fuzzy { items } => items.into_iter().map(|(_, item)| item).collect(),
+            State::Fuzzy { items } => items.into_iter().map(|(_, _, item)| item).collect(),
+            State::Regex { items, .. } => items.into_iter().map(|(_, item)| item).collect(),
+        }
+    }
+
+    /// Like [`Self::results`], but also returns the char positions in each
+    /// result's haystack that matched the needle, so a menu can bold or
+    /// color them. For prefix matches, the indices are just the contiguous
+    /// range covered by the needle; regex matches have no natural positions
+    /// to highlight, so they come back empty.
+    pub fn results_with_match_indices(self) -> Vec<(T, Vec<usize>)> {
+        let needle = self.needle;
+        let needle_len = needle.chars().count();
+        match self.state {
+            State::Prefix { items } => items
+                .into_iter()
+                .map(|(haystack, item)| {
+                    // Without `positional`, the needle may sit anywhere in the haystack.
+                    let start = haystack
+                        .find(&needle)
+                        .map(|byte| haystack[..byte].chars().count())
+                        .unwrap_or(0);
+                    (item, (start..start + needle_len).collect())
+                })
+                .collect(),
+            State::Fuzzy { items } => items
+                .into_iter()
+                .map(|(_, indices, item)| (item, indices))
+                .collect(),
+            State::Regex { items, .. } => {
+                items.into_iter().map(|(_, item)| (item, Vec::new())).collect()
+            }
         }
     }
 }
@@ -145,6 +241,19 @@ impl NuMatcher<SemanticSuggestion> {
     pub fn add_semantic_suggestion(&mut self, suggestion: SemanticSuggestion) -> bool {
         self.add(suggestion.suggestion.value.clone(), suggestion)
     }
+
+    /// Like [`Self::results`], but copies each match's matched char
+    /// positions into `SemanticSuggestion::match_indices` so the menu can
+    /// highlight them.
+    pub fn results_tagged(self) -> Vec<SemanticSuggestion> {
+        self.results_with_match_indices()
+            .into_iter()
+            .map(|(mut suggestion, indices)| {
+                suggestion.match_indices = indices;
+                suggestion
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug)]
@@ -167,6 +276,10 @@ pub struct CompletionOptions {
     pub case_sensitive: bool,
     pub positional: bool,
     pub match_algorithm: MatchAlgorithm,
+    /// Paths matching any of these globs (relative to the directory being
+    /// completed in) are dropped before they become suggestions, e.g. to
+    /// hide `node_modules` or `.git` in file/directory completions.
+    pub exclude: Option<GlobSet>,
 }
 
 impl Default for CompletionOptions {
@@ -175,8 +288,38 @@ impl Default for CompletionOptions {
             case_sensitive: true,
             positional: true,
             match_algorithm: MatchAlgorithm::Prefix,
+            exclude: None,
+        }
+    }
+}
+
+impl CompletionOptions {
+    /// Builds options with [`Self::exclude`] compiled from `exclude_patterns`
+    /// (as configured by the user), and everything else at its default.
+    pub fn with_exclude_patterns(exclude_patterns: &[String]) -> Self {
+        Self {
+            exclude: build_exclude_globset(exclude_patterns),
+            ..Self::default()
+        }
+    }
+}
+
+/// Compiles `patterns` (glob syntax, e.g. from a user's config list) into a
+/// single [`GlobSet`] for [`CompletionOptions::exclude`]. An individual
+/// invalid pattern is skipped rather than failing the whole set; an empty
+/// or entirely-invalid pattern list compiles to no exclusions at all.
+pub fn build_exclude_globset(patterns: &[String]) -> Option<GlobSet> {
+    if patterns.is_empty() {
+        return None;
+    }
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        if let Ok(glob) = Glob::new(pattern) {
+            builder.add(glob);
         }
     }
+    builder.build().ok()
 }
 
 #[cfg(test)]
@@ -212,6 +355,7 @@ mod test {
                 case_sensitive: false,
                 positional: false,
                 match_algorithm: MatchAlgorithm::Prefix,
+                exclude: None,
             },
             haystacks,
             expected,
@@ -229,6 +373,47 @@ mod test {
                 case_sensitive: false,
                 positional: false,
                 match_algorithm: MatchAlgorithm::Fuzzy,
+                exclude: None,
+            },
+            haystacks,
+            expected,
+        );
+    }
+
+    #[rstest]
+    #[case("ba", "bar", &[0, 1])]
+    #[case("gco", "git checkout", &[0, 4, 9])]
+    fn fuzzy_match_indices(
+        #[case] needle: &str,
+        #[case] haystack: &str,
+        #[case] expected: &[usize],
+    ) {
+        let mut matcher = NuMatcher::new(
+            needle,
+            &CompletionOptions {
+                case_sensitive: false,
+                positional: false,
+                match_algorithm: MatchAlgorithm::Fuzzy,
+                exclude: None,
+            },
+        );
+        matcher.add(haystack, haystack);
+        let results = matcher.results_with_match_indices();
+        assert_eq!(results, vec![(haystack, expected.to_vec())]);
+    }
+
+    #[rstest]
+    #[case("", &["foo", "bar", "baz"], &["bar", "baz", "foo"])]
+    #[case("^git (sw|co)", &["git switch", "git checkout", "git commit"], &["git checkout", "git switch"])]
+    #[case("[", &["foo", "bar"], &["bar", "foo"])]
+    fn regex_match(#[case] needle: &str, #[case] haystacks: &[&str], #[case] expected: &[&str]) {
+        run_match_algorithm_test(
+            needle,
+            &CompletionOptions {
+                case_sensitive: false,
+                positional: false,
+                match_algorithm: MatchAlgorithm::Regex,
+                exclude: None,
             },
             haystacks,
             expected,
@@ -248,9 +433,37 @@ mod test {
                 case_sensitive: false,
                 positional,
                 match_algorithm,
+                exclude: None,
             },
             &["Buppercase", "blowercase"],
             &["blowercase", "Buppercase"],
         );
     }
+
+    #[rstest]
+    // Both haystacks match "ab" right after a `_` separator, so they earn
+    // the same word-boundary/consecutive-match bonus and tie on score;
+    // the earlier match position (lower index) wins the tie.
+    #[case("ab", &["xy_ab", "x_ab"], &["x_ab", "xy_ab"])]
+    // Equal skim score and match position: shorter haystack wins.
+    #[case("ab", &["abyz", "ab"], &["ab", "abyz"])]
+    // Equal skim score, position and length: falls back to alphabetical.
+    #[case("ab", &["abz", "aby"], &["aby", "abz"])]
+    fn fuzzy_match_tie_break(
+        #[case] needle: &str,
+        #[case] haystacks: &[&str],
+        #[case] expected: &[&str],
+    ) {
+        run_match_algorithm_test(
+            needle,
+            &CompletionOptions {
+                case_sensitive: false,
+                positional: false,
+                match_algorithm: MatchAlgorithm::Fuzzy,
+                exclude: None,
+            },
+            haystacks,
+            expected,
+        );
+    }
 }
@@ -1,6 +1,9 @@
 use crate::completions::{
-    completion_common::{adjust_if_intermediate, complete_item, AdjustView},
-    Completer, CompletionOptions,
+    completion_common::{
+        adjust_if_intermediate, complete_item, display_basename, path_depth, AdjustView,
+        CompletionIntent,
+    },
+    Completer, CompletionOptions, SuggestionKind,
 };
 use nu_protocol::{
     engine::{Stack, StateWorkingSet},
@@ -11,6 +14,11 @@ use std::path::Path;
 
 use super::SemanticSuggestion;
 
+/// Completes directories, marking results with [`SuggestionKind::Directory`]
+/// (defined in `completions::base`, re-exported from `completions/mod.rs`)
+/// so the dispatcher can decide compose-vs-confirm behavior. The
+/// `CompletionOptions` (including `exclude`) this completer runs with are
+/// built from config and handed in by the dispatcher, not by this module.
 #[derive(Clone, Default)]
 pub struct DirectoryCompletion {}
 
@@ -45,20 +53,27 @@ impl Completer for DirectoryCompletion {
             stack,
         )
         .into_iter()
-        .map(move |(span, _, value, style)| SemanticSuggestion {
-            suggestion: Suggestion {
-                value,
-                description: None,
-                style,
-                extra: None,
-                span: reedline::Span {
-                    start: span.start - offset,
-                    end: span.end - offset,
+        .map(move |(span, _, value, style, isdir)| {
+            // Directories (the only kind of result here) are "navigable":
+            // the value ends in a separator and no whitespace is appended,
+            // so the next completion continues inside the accepted directory.
+            let append_whitespace = CompletionIntent::for_path(isdir).append_whitespace();
+            let description = Some(display_basename(&value, isdir));
+            SemanticSuggestion {
+                suggestion: Suggestion {
+                    value,
+                    description,
+                    style,
+                    extra: None,
+                    span: reedline::Span {
+                        start: span.start - offset,
+                        end: span.end - offset,
+                    },
+                    append_whitespace,
                 },
-                append_whitespace: false,
-            },
-            // TODO????
-            kind: None,
+                kind: Some(SuggestionKind::Directory),
+                match_indices: Vec::new(),
+            }
         })
         .collect();
 
@@ -80,6 +95,10 @@ impl Completer for DirectoryCompletion {
             }
         }
 
+        // Within each group, prefer nearer (shallower) paths first.
+        non_hidden.sort_by_key(path_depth);
+        hidden.sort_by_key(path_depth);
+
         // Append the hidden folders to the non hidden vec to avoid creating a new vec
         non_hidden.append(&mut hidden);
 
@@ -1,4 +1,4 @@
-use crate::completions::{Completer, CompletionOptions};
+use crate::completions::{Completer, CompletionOptions, SuggestionKind};
 use nu_protocol::{
     engine::{Stack, StateWorkingSet},
     Span,
@@ -7,8 +7,8 @@ use reedline::Suggestion;
 use std::path::{is_separator, Path, MAIN_SEPARATOR as SEP, MAIN_SEPARATOR_STR};
 
 use super::{
-    completion_common::{complete_item, sort_suggestions},
-    SemanticSuggestion, SortBy,
+    completion_common::{complete_item, display_basename, path_depth},
+    sort_suggestions, SemanticSuggestion, SortBy,
 };
 
 #[derive(Clone, Default)]
@@ -100,7 +100,7 @@ impl Completer for DotNuCompletion {
             stack,
         )
         .into_iter()
-        .filter(move |(_, search_dir, value, _)| {
+        .filter(move |(_, search_dir, value, _, _isdir)| {
             // Different base dir, so we list the .nu files or folders
             if !is_current_folder {
                 value.ends_with(".nu") || value.ends_with(SEP)
@@ -113,23 +113,31 @@ impl Completer for DotNuCompletion {
                 }
             }
         })
-        .map(move |(span, _, value, style)| SemanticSuggestion {
-            suggestion: Suggestion {
-                value,
-                description: None,
-                style,
-                extra: None,
-                span: reedline::Span {
-                    start: span.start - offset,
-                    end: span.end - offset,
+        .map(move |(span, _, value, style, isdir)| {
+            let description = Some(display_basename(&value, isdir));
+            SemanticSuggestion {
+                suggestion: Suggestion {
+                    value,
+                    description,
+                    style,
+                    extra: None,
+                    span: reedline::Span {
+                        start: span.start - offset,
+                        end: span.end - offset,
+                    },
+                    append_whitespace: true,
                 },
-                append_whitespace: true,
-            },
-            // TODO????
-            kind: None,
+                kind: isdir.then_some(SuggestionKind::Directory),
+                match_indices: Vec::new(),
+            }
         })
         .collect();
 
-        sort_suggestions(&prefix_str, output, SortBy::Ascending)
+        let mut output = sort_suggestions(&prefix_str, output, SortBy::Ascending);
+        // Then order by the depth of each value, shallower first. The sort is
+        // stable, so values of equal depth keep the alphabetical order from
+        // above. The depth is that of the inserted value, not of the search dir.
+        output.sort_by_key(path_depth);
+        output
     }
 }
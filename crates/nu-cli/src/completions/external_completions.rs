@@ -0,0 +1,200 @@
+use std::{
+    collections::HashMap,
+    io::Read,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+use nu_parser::FlatShape;
+use nu_protocol::{
+    engine::{Stack, StateWorkingSet},
+    Span,
+};
+use reedline::Suggestion;
+
+use super::{
+    completion_options::NuMatcher, Completer, CompletionOptions, SemanticSuggestion,
+    SuggestionKind,
+};
+
+/// How long we're willing to wait for an external program to answer a
+/// `--complete` request before giving up and falling back to no completions.
+const EXTERNAL_COMPLETION_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Maps an external command name to the leading part of the invocation used
+/// to ask it for argument completions, e.g. `"cargo" -> ["cargo", "--complete"]`;
+/// `--index <N> -- <word0> <word1> ...` is appended to it.
+///
+/// If a command has no entry, [`ExternalCompletion`] falls back to the
+/// convention `<cmd> --complete --index <N> -- <word0> <word1> ...`.
+pub type ExternalCompleterRegistry = HashMap<String, Vec<String>>;
+
+/// Completes the arguments of an external (non-Nushell) command by asking
+/// the command itself for candidates, similar to clap_complete's dynamic
+/// completion protocol.
+pub struct ExternalCompletion {
+    flattened: Vec<(Span, FlatShape)>,
+    registry: ExternalCompleterRegistry,
+}
+
+impl ExternalCompletion {
+    pub fn new(flattened: Vec<(Span, FlatShape)>, registry: ExternalCompleterRegistry) -> Self {
+        Self {
+            flattened,
+            registry,
+        }
+    }
+
+    /// Builds the argv used to invoke `program` in completion mode: its
+    /// registry entry (or `<program> --complete`), then `--index <index> --`
+    /// and `words`, where `index` is the word under the cursor.
+    fn build_invocation(&self, program: &str, words: &[String], index: usize) -> Vec<String> {
+        let mut invocation = match self.registry.get(program) {
+            Some(template) => template.clone(),
+            None => vec![program.to_string(), "--complete".into()],
+        };
+        invocation.push("--index".into());
+        invocation.push(index.to_string());
+        invocation.push("--".into());
+        invocation.extend(words.iter().cloned());
+        invocation
+    }
+
+    /// Runs the completion invocation and parses newline-separated
+    /// `value` or `value\tdescription` candidates from stdout. Any failure
+    /// (spawn error, non-zero exit, timeout) is treated as "no candidates".
+    fn run_invocation(invocation: &[String], cwd: &Path) -> Vec<(String, Option<String>)> {
+        let Some((program, args)) = invocation.split_first() else {
+            return vec![];
+        };
+
+        let mut command = std::process::Command::new(program);
+        command
+            .args(args)
+            .current_dir(cwd)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::null());
+
+        let Ok(mut child) = command.spawn() else {
+            return vec![];
+        };
+
+        // Only the stdout handle is handed to the reader thread; `child`
+        // itself stays here so we can still kill it if it doesn't answer
+        // in time instead of leaking the process.
+        let Some(mut stdout) = child.stdout.take() else {
+            return vec![];
+        };
+
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            let _ = stdout.read_to_end(&mut buf);
+            let _ = tx.send(buf);
+        });
+
+        let Ok(buf) = rx.recv_timeout(EXTERNAL_COMPLETION_TIMEOUT) else {
+            let _ = child.kill();
+            let _ = child.wait();
+            return vec![];
+        };
+
+        match child.wait() {
+            Ok(status) if status.success() => {}
+            _ => return vec![],
+        }
+
+        String::from_utf8_lossy(&buf)
+            .lines()
+            .map(|line| match line.split_once('\t') {
+                Some((value, description)) => {
+                    (value.to_string(), Some(description.to_string()))
+                }
+                None => (line.to_string(), None),
+            })
+            .collect()
+    }
+}
+
+impl Completer for ExternalCompletion {
+    fn fetch(
+        &mut self,
+        working_set: &StateWorkingSet,
+        _stack: &Stack,
+        prefix: Vec<u8>,
+        span: Span,
+        offset: usize,
+        pos: usize,
+        options: &CompletionOptions,
+    ) -> Vec<SemanticSuggestion> {
+        // Walk back through the already-flattened external call to collect
+        // the words that make up this invocation, plus the index of the
+        // word the cursor is currently inside.
+        let call: Vec<_> = self
+            .flattened
+            .iter()
+            .rev()
+            .skip_while(|(call_span, _)| call_span.start > pos)
+            .take_while(|(_, shape)| {
+                matches!(shape, FlatShape::External | FlatShape::ExternalArg)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+
+        let Some((program_span, _)) = call
+            .iter()
+            .find(|(_, shape)| matches!(shape, FlatShape::External))
+        else {
+            return vec![];
+        };
+        let program = String::from_utf8_lossy(working_set.get_span_contents(*program_span))
+            .into_owned();
+
+        let mut words = Vec::new();
+        let mut cursor_index = None;
+        for (call_span, _) in call
+            .iter()
+            .filter(|(_, shape)| matches!(shape, FlatShape::ExternalArg))
+        {
+            if *call_span == span {
+                cursor_index = Some(words.len());
+            }
+            words.push(
+                String::from_utf8_lossy(working_set.get_span_contents(*call_span)).into_owned(),
+            );
+        }
+        let Some(index) = cursor_index else {
+            return vec![];
+        };
+        words[index] = String::from_utf8_lossy(&prefix).into_owned();
+
+        #[allow(deprecated)]
+        let cwd = PathBuf::from(working_set.permanent_state.current_work_dir());
+
+        let invocation = self.build_invocation(&program, &words, index);
+        let candidates = Self::run_invocation(&invocation, &cwd);
+
+        let sugg_span = reedline::Span::new(span.start - offset, span.end - offset);
+        let mut matcher = NuMatcher::new(String::from_utf8_lossy(&prefix), options);
+        for (value, description) in candidates {
+            matcher.add_semantic_suggestion(SemanticSuggestion {
+                suggestion: Suggestion {
+                    value: super::completion_common::escape_path(value, false),
+                    description,
+                    style: None,
+                    extra: None,
+                    span: sugg_span,
+                    append_whitespace: true,
+                },
+                kind: Some(SuggestionKind::External),
+                match_indices: Vec::new(),
+            });
+        }
+
+        matcher.results_tagged()
+    }
+}
@@ -1,16 +1,41 @@
 use crate::completions::{
-    completion_common::{adjust_if_intermediate, complete_item, AdjustView},
-    Completer, CompletionOptions,
+    completion_common::{adjust_if_intermediate, complete_item, display_basename, AdjustView},
+    Completer, CompletionOptions, SuggestionKind,
 };
 use nu_protocol::{
     engine::{Stack, StateWorkingSet},
     Span,
 };
 use reedline::Suggestion;
-use std::path::Path;
+use std::path::{is_separator, Path, PathBuf};
 
 use super::SemanticSuggestion;
 
+/// The basename used as the menu label, plus (for symlinks to files) the
+/// target they point at, e.g. `target -> ../real/file`. Directories,
+/// symlinked or not, get the basename alone.
+fn describe(cwd: &Path, value: &str, isdir: bool) -> String {
+    let base = display_basename(value, isdir);
+    if isdir {
+        return base;
+    }
+
+    let unquoted = value.trim_matches(['\'', '"', '`']);
+    let relative = unquoted
+        .strip_prefix('~')
+        .map(|rest| rest.trim_start_matches(is_separator))
+        .unwrap_or(unquoted);
+    let full: PathBuf = cwd.join(relative);
+
+    match std::fs::symlink_metadata(&full) {
+        Ok(meta) if meta.file_type().is_symlink() => match std::fs::read_link(&full) {
+            Ok(target) => format!("{base} -> {}", target.display()),
+            Err(_) => base,
+        },
+        _ => base,
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct FileCompletion {}
 
@@ -48,24 +73,28 @@ impl Completer for FileCompletion {
             stack,
         )
         .into_iter()
-        .map(move |(span, _, value, style)| SemanticSuggestion {
-            suggestion: Suggestion {
-                value,
-                description: None,
-                style,
-                extra: None,
-                span: reedline::Span {
-                    start: span.start - offset,
-                    end: span.end - offset,
+        .map(move |(span, cwd, value, style, isdir)| {
+            let description = Some(describe(&cwd, &value, isdir));
+            SemanticSuggestion {
+                suggestion: Suggestion {
+                    value,
+                    description,
+                    style,
+                    extra: None,
+                    span: reedline::Span {
+                        start: span.start - offset,
+                        end: span.end - offset,
+                    },
+                    append_whitespace: false,
                 },
-                append_whitespace: false,
-            },
-            // TODO????
-            kind: None,
+                kind: isdir.then_some(SuggestionKind::Directory),
+                match_indices: Vec::new(),
+            }
         })
         .collect();
 
-        // Sort results prioritizing the non hidden folders
+        // Sort results prioritizing the non hidden folders, then directories
+        // ahead of files within each of those groups.
 
         // Separate the results between hidden and non hidden
         let mut hidden: Vec<SemanticSuggestion> = vec![];
@@ -85,6 +114,14 @@ impl Completer for FileCompletion {
             }
         }
 
+        // `sort_by_key` is stable, so entries with the same kind keep the
+        // relative order `NuMatcher` already gave them.
+        let is_file = |item: &SemanticSuggestion| {
+            !matches!(item.kind, Some(SuggestionKind::Directory))
+        };
+        non_hidden.sort_by_key(is_file);
+        hidden.sort_by_key(is_file);
+
         // Append the hidden folders to the non hidden vec to avoid creating a new vec
         non_hidden.append(&mut hidden);
 
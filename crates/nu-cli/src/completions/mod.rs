@@ -0,0 +1,20 @@
+mod base;
+mod command_completions;
+mod completion_common;
+mod completion_options;
+mod directory_completions;
+mod dotnu_completions;
+mod external_completions;
+mod file_completions;
+
+pub use base::{sort_suggestions, Completer, SemanticSuggestion, SortBy, SuggestionKind};
+pub use command_completions::CommandCompletion;
+pub use completion_common::{
+    adjust_if_intermediate, complete_item, display_basename, escape_path, path_depth, AdjustView,
+    CompletionIntent,
+};
+pub use completion_options::{build_exclude_globset, CompletionOptions, MatchAlgorithm, NuMatcher};
+pub use directory_completions::DirectoryCompletion;
+pub use dotnu_completions::DotNuCompletion;
+pub use external_completions::{ExternalCompleterRegistry, ExternalCompletion};
+pub use file_completions::FileCompletion;